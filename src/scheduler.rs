@@ -21,22 +21,257 @@
 
 //! Global coroutine scheduler
 
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::ptr;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, Condvar};
 use std::sync::mpsc::Sender;
 use std::default::Default;
 use std::any::Any;
 use std::thread;
+use std::time::Duration;
 
 use deque::Stealer;
+use num_cpus;
 
 use runtime::processor::{Processor, ProcMessage};
 use coroutine::{Coroutine, SendableCoroutinePtr};
 use options::Options;
 
+/// A task that has been boxed up so it can be stored and called later
+/// regardless of its concrete closure type.
+trait FnBox {
+    fn call_box(self: Box<Self>);
+}
+
+impl<F: FnOnce()> FnBox for F {
+    fn call_box(self: Box<F>) {
+        (*self)()
+    }
+}
+
+thread_local!(
+    // Non-null on a thread that belongs to a `NativeRuntime`: points at the
+    // `Scheduler` that owns it, since such a thread has no `Processor` to
+    // fetch it from.
+    static NATIVE_SCHED: Cell<*const Scheduler> = Cell::new(ptr::null())
+);
+
+/// Dispatches spawned work onto whatever execution resource a `Scheduler`
+/// is configured to use.
+///
+/// `Scheduler` hard-wires most of its behaviour to the M:N,
+/// work-stealing `Processor` pool, but some workloads are all blocking and
+/// gain nothing from green scheduling. This trait is the seam between the
+/// two: `GreenRuntime` is the existing `Processor`-backed behaviour, and
+/// `NativeRuntime` maps every spawned unit onto its own OS thread while
+/// keeping the same `JoinHandle<T>` API.
+trait Runtime: Send + Sync {
+    fn spawn_opts(&self, sched: &'static Scheduler, f: Box<FnBox + Send>, opts: Options);
+
+    /// Whether this runtime runs tasks as plain OS threads rather than
+    /// `Processor`-scheduled coroutines. `Scheduler::run` uses this to
+    /// decide whether it needs to start any `Processor` workers at all.
+    fn is_native(&self) -> bool {
+        false
+    }
+
+    /// Suspend the calling unit of work so something else can run, without
+    /// necessarily blocking it on anything in particular.
+    fn sched(&self, sched: &'static Scheduler);
+
+    /// Block the calling unit of work until a matching call to `ready`
+    /// wakes it back up.
+    fn block(&self, sched: &'static Scheduler);
+
+    /// Wake whatever is parked in a matching call to `block`. `coro`
+    /// identifies which parked coroutine to wake under `GreenRuntime`; it
+    /// is meaningless for `NativeRuntime`; see its impl.
+    unsafe fn ready(&self, sched: &'static Scheduler, coro: *mut Coroutine);
+}
+
+struct GreenRuntime;
+
+impl Runtime for GreenRuntime {
+    fn spawn_opts(&self, sched: &'static Scheduler, f: Box<FnBox + Send>, opts: Options) {
+        Processor::current().spawn_opts(Box::new(move || f.call_box()), opts);
+
+        let &(ref lock, ref cond) = &*sched.starving_lock;
+        let _ = lock.lock().unwrap();
+        cond.notify_one();
+    }
+
+    fn sched(&self, _sched: &'static Scheduler) {
+        Processor::current().sched();
+    }
+
+    fn block(&self, _sched: &'static Scheduler) {
+        Processor::current().block();
+    }
+
+    unsafe fn ready(&self, _sched: &'static Scheduler, coro: *mut Coroutine) {
+        Processor::current().ready(coro);
+    }
+}
+
+struct NativeRuntime;
+
+impl Runtime for NativeRuntime {
+    fn spawn_opts(&self, sched: &'static Scheduler, f: Box<FnBox + Send>, _opts: Options) {
+        thread::spawn(move || {
+            NATIVE_SCHED.with(|c| c.set(sched as *const Scheduler));
+            f.call_box();
+
+            // `GreenRuntime` tasks get this via `Scheduler::finished`, run
+            // from the coroutine-completion path; a native task has no
+            // such path, so account for its completion here instead.
+            sched.work_counts.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+
+    fn is_native(&self) -> bool {
+        true
+    }
+
+    fn sched(&self, _sched: &'static Scheduler) {
+        // Real OS threads are already scheduled fairly by the OS; giving
+        // up the rest of the current timeslice is the closest analogue to
+        // a coroutine yielding.
+        thread::yield_now();
+    }
+
+    fn block(&self, sched: &'static Scheduler) {
+        // There is no coroutine to park, but the thread genuinely needs to
+        // stop running until `ready` wakes it — callers like the blocking
+        // `::sync::mpsc` receiver backing `JoinHandle`/`Generator` rely on
+        // `block()` actually suspending, not busy-looping, between polls
+        // of their own condition. `native_park` is a plain condvar shared
+        // by every native thread on this `Scheduler`; `ready` broadcasts
+        // on it, so a blocked thread wakes, re-checks whatever it was
+        // waiting on, and re-blocks if it still isn't ready — the same
+        // spurious-wakeup-tolerant contract `BlockingPool::worker_loop`
+        // already relies on below.
+        let &(ref lock, ref cond) = &*sched.native_park;
+        let guard = lock.lock().unwrap();
+        let _ = cond.wait(guard).unwrap();
+    }
+
+    unsafe fn ready(&self, sched: &'static Scheduler, _coro: *mut Coroutine) {
+        // `coro` identifies a specific parked coroutine under
+        // `GreenRuntime`; native tasks have no `Coroutine`, so there is no
+        // such identity to target. Broadcasting instead is safe under the
+        // recheck-and-reblock contract described in `block()` above: every
+        // native thread parked on `native_park` wakes, and only the one
+        // whose condition is actually satisfied stays awake.
+        let &(ref lock, ref cond) = &*sched.native_park;
+        let _ = lock.lock().unwrap();
+        cond.notify_all();
+    }
+}
+
+/// Default number of OS threads the blocking pool will grow to; past that,
+/// new `spawn_blocking` tasks are queued until a thread frees up.
+const DEFAULT_BLOCKING_POOL_CAP: usize = 128;
+
+/// How long a blocking-pool thread sits idle before it is reaped.
+const DEFAULT_BLOCKING_IDLE_TIMEOUT_MS: u64 = 10_000;
+
+/// Default overcommit factor applied by `default_sched_threads` (and so by
+/// `new`/`with_default_workers`): one worker per CPU, no oversubscription.
+const SCHED_OVERCOMMIT: usize = 1;
+
+/// The number of `Processor` workers a scheduler should default to on this
+/// host: one per available CPU, mirroring the classic
+/// `default_sched_threads * SCHED_OVERCOMMIT` sizing heuristic.
+fn default_sched_threads() -> usize {
+    num_cpus::get() * SCHED_OVERCOMMIT
+}
+
+struct BlockingPoolInner {
+    queue: VecDeque<Box<FnBox + Send>>,
+    live_threads: usize,
+    idle_threads: usize,
+}
+
+/// A pool of real OS threads dedicated to running blocking work.
+///
+/// Unlike `Processor` workers, threads in this pool are grown lazily (up
+/// to `cap`) and reaped after sitting idle for `idle_timeout`, since the
+/// whole point is to absorb bursts of blocking calls without paying for a
+/// permanent thread per call.
+struct BlockingPool {
+    cap: usize,
+    idle_timeout: Duration,
+    inner: Mutex<BlockingPoolInner>,
+    cond: Condvar,
+}
+
+impl BlockingPool {
+    fn new(cap: usize, idle_timeout: Duration) -> BlockingPool {
+        BlockingPool {
+            cap: cap,
+            idle_timeout: idle_timeout,
+            inner: Mutex::new(BlockingPoolInner {
+                queue: VecDeque::new(),
+                live_threads: 0,
+                idle_threads: 0,
+            }),
+            cond: Condvar::new(),
+        }
+    }
+
+    /// Queue `task` for execution, spinning up a new worker thread if the
+    /// pool has spare capacity and no thread is currently idle.
+    fn submit(&'static self, task: Box<FnBox + Send>) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.queue.push_back(task);
+
+        if inner.idle_threads == 0 && inner.live_threads < self.cap {
+            inner.live_threads += 1;
+            thread::spawn(move || self.worker_loop());
+        }
+
+        self.cond.notify_one();
+    }
+
+    fn worker_loop(&self) {
+        let mut inner = self.inner.lock().unwrap();
+
+        loop {
+            match inner.queue.pop_front() {
+                Some(task) => {
+                    drop(inner);
+                    task.call_box();
+                    inner = self.inner.lock().unwrap();
+                }
+                None => {
+                    inner.idle_threads += 1;
+                    let (guard, timeout_result) = self.cond
+                                                       .wait_timeout(inner, self.idle_timeout)
+                                                       .unwrap();
+                    inner = guard;
+                    inner.idle_threads -= 1;
+
+                    if timeout_result.timed_out() && inner.queue.is_empty() {
+                        inner.live_threads -= 1;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// A handle that could join the coroutine
+///
+/// Dropping a `JoinHandle` joins the coroutine by default, so a scope that
+/// spawns work and lets its handles fall out of scope still waits for that
+/// work to finish, giving structured-concurrency guarantees. Call
+/// `detach()` to opt out and let the coroutine run unsupervised instead.
 pub struct JoinHandle<T> {
     result: ::sync::mpsc::Receiver<Result<T, Box<Any + Send + 'static>>>,
+    detached: bool,
 }
 
 impl<T> JoinHandle<T> {
@@ -46,16 +281,89 @@ impl<T> JoinHandle<T> {
     pub fn join(&self) -> Result<T, Box<Any + Send + 'static>> {
         self.result.recv().expect("Failed to receive from the channel")
     }
+
+    /// Detach the coroutine, letting it run to completion on its own.
+    ///
+    /// Without calling this, dropping the handle blocks and joins the
+    /// coroutine; `detach()` opts out of that and consumes the handle so
+    /// the coroutine is no longer supervised by anyone.
+    pub fn detach(mut self) {
+        self.detached = true;
+    }
+}
+
+impl<T> Drop for JoinHandle<T> {
+    fn drop(&mut self) {
+        if !self.detached {
+            let _ = self.result.recv();
+        }
+    }
 }
 
 unsafe impl<T: Send> Send for JoinHandle<T> {}
 
+/// Passed into a generator coroutine's closure so it can yield values back
+/// to its `Generator`.
+///
+/// `yield_` hands `value` to the consumer and then blocks until the
+/// consumer asks for the next one, so producer and consumer alternate
+/// without busy-waiting: none of `f`'s code runs until `Generator::next()`
+/// is called, and `f` makes no further progress past a `yield_` until the
+/// next `next()`.
+pub struct Yielder<Y> {
+    value_tx: ::sync::mpsc::Sender<Option<Y>>,
+    resume_rx: ::sync::mpsc::Receiver<()>,
+}
+
+impl<Y: Send + 'static> Yielder<Y> {
+    /// Suspend the coroutine, handing `value` to the consumer, until the
+    /// consumer pulls the next one via `Generator::next()`.
+    ///
+    /// If the `Generator` was dropped, there is nobody left to resume this
+    /// coroutine, so rather than fall through and race to completion
+    /// unsupervised, this panics. A `Generator` that is still alive but
+    /// never polls again leaves the coroutine blocked here. The
+    /// panic is caught by the same `::try` wrapper every spawned coroutine
+    /// already runs under, and surfaces through its `JoinHandle` as an
+    /// `Err` like any other coroutine panic.
+    pub fn yield_(&self, value: Y) {
+        if self.value_tx.send(Some(value)).is_err() {
+            panic!("Yielder::yield_ called after its Generator was dropped");
+        }
+        if self.resume_rx.recv().is_err() {
+            panic!("Yielder::yield_ parked after its Generator was dropped");
+        }
+    }
+}
+
+/// The consumer side of a `Scheduler::spawn_generator` pair.
+pub struct Generator<Y> {
+    value_rx: ::sync::mpsc::Receiver<Option<Y>>,
+    resume_tx: ::sync::mpsc::Sender<()>,
+}
+
+impl<Y: Send + 'static> Generator<Y> {
+    /// Resume the generator coroutine and wait for its next yielded value,
+    /// or `None` once the generator has run to completion.
+    pub fn next(&self) -> Option<Y> {
+        let _ = self.resume_tx.send(());
+
+        match self.value_rx.recv() {
+            Ok(value) => value,
+            Err(_) => None,
+        }
+    }
+}
+
 /// Coroutine scheduler
 pub struct Scheduler {
     work_counts: AtomicUsize,
     proc_handles: Mutex<Vec<(Sender<ProcMessage>, Stealer<SendableCoroutinePtr>)>>,
     expected_worker_count: usize,
     starving_lock: Arc<(Mutex<usize>, Condvar)>,
+    native_park: Arc<(Mutex<()>, Condvar)>,
+    blocking_pool: BlockingPool,
+    runtime: Box<Runtime>,
 }
 
 unsafe impl Send for Scheduler {}
@@ -63,15 +371,42 @@ unsafe impl Sync for Scheduler {}
 
 impl Scheduler {
     /// Create a scheduler with default configurations
+    ///
+    /// The worker count defaults to the host's available parallelism; see
+    /// `with_default_workers`.
     pub fn new() -> Scheduler {
+        Scheduler::with_default_workers()
+    }
+
+    fn with_worker_count(workers: usize) -> Scheduler {
         Scheduler {
             work_counts: AtomicUsize::new(0),
             proc_handles: Mutex::new(Vec::new()),
-            expected_worker_count: 1,
+            expected_worker_count: workers,
             starving_lock: Arc::new((Mutex::new(0), Condvar::new())),
+            native_park: Arc::new((Mutex::new(()), Condvar::new())),
+            blocking_pool: BlockingPool::new(DEFAULT_BLOCKING_POOL_CAP,
+                                              Duration::from_millis(DEFAULT_BLOCKING_IDLE_TIMEOUT_MS)),
+            runtime: Box::new(GreenRuntime),
         }
     }
 
+    /// Switch to a 1:1 native-thread runtime: every `spawn`/`spawn_opts`
+    /// call runs on its own `std::thread` instead of a green coroutine
+    /// scheduled over `Processor` workers. Useful for workloads that are
+    /// all blocking and get nothing out of M:N scheduling. `JoinHandle`,
+    /// `sched()` and `block()` keep working the same way from the
+    /// caller's point of view.
+    pub fn with_native_runtime(mut self) -> Scheduler {
+        self.runtime = Box::new(NativeRuntime);
+        self
+    }
+
+    /// Create a scheduler with one worker per available CPU.
+    pub fn with_default_workers() -> Scheduler {
+        Scheduler::with_worker_count(default_sched_threads())
+    }
+
     /// Set the number of workers
     pub fn with_workers(mut self, workers: usize) -> Scheduler {
         assert!(workers >= 1, "Must have at least one worker");
@@ -79,18 +414,39 @@ impl Scheduler {
         self
     }
 
+    /// Oversubscribe workers relative to the host's CPU count, useful for
+    /// I/O-bound workloads where workers spend most of their time blocked
+    /// on I/O rather than competing for CPU. `factor` multiplies the CPU
+    /// count, so `with_overcommit(2)` on an 8-core host runs 16 workers.
+    pub fn with_overcommit(mut self, factor: usize) -> Scheduler {
+        assert!(factor >= 1, "Overcommit factor must be at least 1");
+        self.expected_worker_count = default_sched_threads() * factor;
+        self
+    }
+
+    /// The number of `Processor` workers this scheduler will run.
+    pub fn worker_count(&self) -> usize {
+        self.expected_worker_count
+    }
+
     /// Get the global Scheduler
     #[doc(hidden)]
     #[inline]
     pub fn instance() -> &'static Scheduler {
-        Processor::current().scheduler()
+        let native = NATIVE_SCHED.with(|c| c.get());
+        if !native.is_null() {
+            unsafe { &*native }
+        } else {
+            Processor::current().scheduler()
+        }
     }
 
     /// A coroutine is ready for schedule
     #[doc(hidden)]
     #[inline]
     pub unsafe fn ready(coro: *mut Coroutine) {
-        Processor::current().ready(coro);
+        let sched = Scheduler::instance();
+        sched.runtime.ready(sched, coro);
     }
 
     #[doc(hidden)]
@@ -138,7 +494,8 @@ impl Scheduler {
         where F: FnOnce() -> T + Send + 'static,
               T: Send + 'static
     {
-        Scheduler::instance().work_counts.fetch_add(1, Ordering::SeqCst);
+        let sched = Scheduler::instance();
+        sched.work_counts.fetch_add(1, Ordering::SeqCst);
 
         let (tx, rx) = ::sync::mpsc::channel();
         let wrapper = move || {
@@ -147,13 +504,91 @@ impl Scheduler {
             // No matter whether it is panicked or not, the result will be sent to the channel
             let _ = tx.send(ret); // Just ignore if it failed
         };
-        Processor::current().spawn_opts(Box::new(wrapper), opts);
+        sched.runtime.spawn_opts(sched, Box::new(wrapper), opts);
 
-        let &(ref lock, ref cond) = &*Scheduler::instance().starving_lock;
-        let _ = lock.lock().unwrap();
-        cond.notify_one();
+        JoinHandle { result: rx, detached: false }
+    }
+
+    /// Spawn a blocking task on a dedicated pool of OS threads.
+    ///
+    /// Unlike `spawn`/`spawn_opts`, `f` is expected to make real blocking
+    /// calls (file I/O, DNS resolution, CPU-bound work, ...), so it is
+    /// never scheduled onto a work-stealing `Processor` worker: doing so
+    /// would stall that worker and, through the `starving_lock` condvar,
+    /// could eventually starve the whole pool. Instead `f` runs on a
+    /// thread from a separately-managed blocking pool that is grown
+    /// lazily up to a cap and whose idle threads are reaped after a
+    /// timeout. The result flows back through the same channel-backed
+    /// `JoinHandle` used by coroutine spawns.
+    pub fn spawn_blocking<F, T>(f: F) -> JoinHandle<T>
+        where F: FnOnce() -> T + Send + 'static,
+              T: Send + 'static
+    {
+        let sched = Scheduler::instance();
+
+        // Counted the same way as coroutine work, so `work_count()` keeps
+        // reflecting outstanding work regardless of where it runs.
+        sched.work_counts.fetch_add(1, Ordering::SeqCst);
+
+        let (tx, rx) = ::sync::mpsc::channel();
+        let task = move || {
+            let ret = unsafe { ::try(move || f()) };
 
-        JoinHandle { result: rx }
+            // Decrement before sending the result, so that by the time
+            // `join()` observes the value, `work_count()` already
+            // reflects it being done. Uses the `sched` reference already
+            // resolved above rather than `Scheduler::instance()`: this
+            // closure runs on a BlockingPool OS thread, which is neither
+            // `NATIVE_SCHED`-tagged nor a `Processor` worker, so
+            // `Scheduler::instance()` would have no way to resolve the
+            // current scheduler here.
+            sched.work_counts.fetch_sub(1, Ordering::SeqCst);
+
+            let _ = tx.send(ret);
+        };
+
+        sched.blocking_pool.submit(Box::new(task));
+
+        JoinHandle { result: rx, detached: false }
+    }
+
+    /// Spawn a generator coroutine: `f` receives a `Yielder<Y>` it can use
+    /// to hand intermediate values back to the returned `Generator<Y>`,
+    /// suspending itself between each one, and its final return value `T`
+    /// flows through the returned `JoinHandle<T>` exactly like a normal
+    /// `spawn`.
+    ///
+    /// None of `f` runs until the first `Generator::next()` call, mirroring
+    /// how a generator's body doesn't execute until it is first resumed.
+    pub fn spawn_generator<F, Y, T>(f: F) -> (Generator<Y>, JoinHandle<T>)
+        where F: FnOnce(Yielder<Y>) -> T + Send + 'static,
+              Y: Send + 'static,
+              T: Send + 'static
+    {
+        let (value_tx, value_rx) = ::sync::mpsc::channel();
+        let (resume_tx, resume_rx) = ::sync::mpsc::channel();
+
+        let body = move || {
+            let _ = resume_rx.recv();
+
+            let final_tx = value_tx.clone();
+            let yielder = Yielder { value_tx: value_tx, resume_rx: resume_rx };
+            let ret = f(yielder);
+
+            // Let a pending (or future) `next()` see that the generator is
+            // done instead of hanging waiting for one last value.
+            let _ = final_tx.send(None);
+
+            ret
+        };
+
+        let join = Scheduler::spawn(body);
+        let generator = Generator {
+            value_rx: value_rx,
+            resume_tx: resume_tx,
+        };
+
+        (generator, join)
     }
 
     /// Run the scheduler
@@ -161,6 +596,10 @@ impl Scheduler {
         where M: FnOnce() -> R + Send + 'static,
               R: Send + 'static
     {
+        if self.runtime.is_native() {
+            return self.run_native(main_fn);
+        }
+
         let the_sched = Arc::new(self);
         let mut handles = Vec::new();
 
@@ -213,18 +652,39 @@ impl Scheduler {
         main_ret
     }
 
+    /// Run `main_fn` directly under the native runtime: there is no
+    /// `Processor` pool to start, so `main_fn` just executes on the
+    /// calling thread, with `Scheduler::instance()` resolved through
+    /// `NATIVE_SCHED` for the duration of the call.
+    ///
+    /// The `Scheduler` is leaked to get a `&'static` reference, the same
+    /// way the green path effectively never frees its `Arc<Scheduler>`
+    /// for the life of the program: native tasks spawned from `main_fn`
+    /// may run on OS threads that outlive this call.
+    fn run_native<M, R>(self, main_fn: M) -> Result<R, Box<Any + Send + 'static>>
+        where M: FnOnce() -> R + Send + 'static,
+              R: Send + 'static
+    {
+        let sched: &'static Scheduler = unsafe { &*Arc::into_raw(Arc::new(self)) };
+        NATIVE_SCHED.with(|c| c.set(sched as *const Scheduler));
+
+        unsafe { ::try(move || main_fn()) }
+    }
 
     /// Suspend the current coroutine
     #[inline]
     pub fn sched() {
-        Processor::current().sched();
+        let sched = Scheduler::instance();
+        sched.runtime.sched(sched);
     }
 
     /// Block the current coroutine
     #[inline]
     pub fn block() {
-        Processor::current().block();
+        let sched = Scheduler::instance();
+        sched.runtime.block(sched);
     }
+
 }
 
 #[cfg(test)]
@@ -241,4 +701,159 @@ mod test {
             })
             .unwrap();
     }
+
+    #[test]
+    fn test_spawn_blocking_runs_off_the_worker_and_rejoins_work_count() {
+        Scheduler::new()
+            .run(|| {
+                let guard = Scheduler::spawn_blocking(|| 1 + 1);
+
+                assert_eq!(2, guard.join().unwrap());
+                assert_eq!(0, Scheduler::instance().work_count());
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_dropping_a_handle_joins_the_coroutine() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        Scheduler::new()
+            .run(|| {
+                let done = Arc::new(AtomicBool::new(false));
+
+                {
+                    let done = done.clone();
+                    drop(Scheduler::spawn(move || done.store(true, Ordering::SeqCst)));
+                }
+
+                assert!(done.load(Ordering::SeqCst));
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_detach_lets_the_coroutine_run_unsupervised() {
+        Scheduler::new()
+            .run(|| {
+                let guard = Scheduler::spawn(|| 1);
+                guard.detach();
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_worker_count_defaults_to_cpu_count_and_honours_overcommit() {
+        let cpus = ::num_cpus::get();
+
+        assert_eq!(cpus, Scheduler::new().worker_count());
+        assert_eq!(cpus, Scheduler::with_default_workers().worker_count());
+        assert_eq!(cpus * 3, Scheduler::new().with_overcommit(3).worker_count());
+        assert_eq!(5, Scheduler::new().with_workers(5).worker_count());
+    }
+
+    #[test]
+    fn test_native_runtime_runs_spawned_tasks_to_completion() {
+        Scheduler::new()
+            .with_native_runtime()
+            .run(|| {
+                let guard = Scheduler::spawn(|| 1 + 1);
+
+                assert_eq!(2, guard.join().unwrap());
+
+                // `work_counts` is decremented on the native thread right
+                // after it sends the joined result, so there is a brief
+                // window after `join()` returns where it hasn't settled
+                // yet; give it a moment rather than asserting on the hair.
+                for _ in 0..1000 {
+                    if Scheduler::instance().work_count() == 0 {
+                        break;
+                    }
+                    thread::sleep(Duration::from_millis(1));
+                }
+                assert_eq!(0, Scheduler::instance().work_count());
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_native_block_parks_the_thread_until_ready_wakes_it() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        Scheduler::new()
+            .with_native_runtime()
+            .run(|| {
+                let woke = Arc::new(AtomicBool::new(false));
+                let woke_in_task = woke.clone();
+
+                let guard = Scheduler::spawn(move || {
+                    Scheduler::block();
+                    woke_in_task.store(true, Ordering::SeqCst);
+                });
+
+                // Give the task a moment to actually park inside
+                // `block()` before waking it, so this exercises the
+                // parked-then-woken path rather than racing it.
+                thread::sleep(Duration::from_millis(50));
+                assert!(!woke.load(Ordering::SeqCst));
+
+                // Native tasks have no `Coroutine`, so the pointer this
+                // would otherwise target is meaningless; see `ready()`'s
+                // native impl.
+                unsafe { Scheduler::ready(ptr::null_mut()) };
+
+                guard.join().unwrap();
+                assert!(woke.load(Ordering::SeqCst));
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_generator_yields_values_then_returns_final_result() {
+        Scheduler::new()
+            .run(|| {
+                let (generator, join) = Scheduler::spawn_generator(|yielder| {
+                    yielder.yield_(1);
+                    yielder.yield_(2);
+                    "done"
+                });
+
+                assert_eq!(Some(1), generator.next());
+                assert_eq!(Some(2), generator.next());
+                assert_eq!(None, generator.next());
+                assert_eq!("done", join.join().unwrap());
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_dropping_a_generator_stops_the_producer_instead_of_racing_it() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        Scheduler::new()
+            .run(|| {
+                let yields_seen = Arc::new(AtomicUsize::new(0));
+                let yields_seen_in_coro = yields_seen.clone();
+
+                let (generator, join) = Scheduler::spawn_generator(move |yielder| {
+                    for i in 0..1000 {
+                        yielder.yield_(i);
+                        yields_seen_in_coro.fetch_add(1, Ordering::SeqCst);
+                    }
+                });
+
+                assert_eq!(Some(0), generator.next());
+                drop(generator);
+
+                // The producer should have panicked (caught by `::try`)
+                // parked in the first `yield_`, rather than falling
+                // through the loop unsupervised.
+                assert!(join.join().is_err());
+                assert_eq!(0, yields_seen.load(Ordering::SeqCst));
+            })
+            .unwrap();
+    }
 }